@@ -1,10 +1,12 @@
 use async_signals::Signals;
 use async_std::{
+    io::{ReadExt, WriteExt},
+    os::unix::net::{UnixListener, UnixStream},
     stream::{Stream, StreamExt},
     task,
 };
 use backtrace::Backtrace;
-use clap::Parser;
+use clap::{ArgAction, Parser, Subcommand};
 use crossterm::{
     cursor::RestorePosition,
     event::{Event, EventStream, KeyCode, KeyEvent, KeyModifiers},
@@ -15,42 +17,114 @@ use crossterm::{
 use dynfmt::Format;
 use notify_rust::Notification;
 use serde::Serialize;
-use std::{collections::HashMap, io::stdout, panic, pin::Pin, time::Duration};
+use std::{
+    collections::HashMap, io::stdout, net::Shutdown, panic, path::PathBuf, pin::Pin, time::Duration,
+};
 
 fn main() -> anyhow::Result<()> {
-    let config = task::block_on(PomodoroConfig::load())?;
-    let app = PomodoroApplication::new(config)?;
-    task::block_on(app.run())?;
+    let args = PomodoroArgs::parse();
+    match &args.command {
+        None => {
+            let config = task::block_on(PomodoroConfig::load(&args))?;
+            let app = PomodoroApplication::new(config, None)?;
+            task::block_on(app.run())?;
+        }
+        Some(PomodoroCommand::Daemon) => {
+            let config = task::block_on(PomodoroConfig::load(&args))?;
+            let app = PomodoroApplication::new(config, Some(socket_path()?))?;
+            task::block_on(app.run())?;
+        }
+        Some(PomodoroCommand::Toggle) => {
+            task::block_on(run_client_command(PomodoroSocketCommand::TogglePause))?
+        }
+        Some(PomodoroCommand::Status) => {
+            task::block_on(run_client_command(PomodoroSocketCommand::Status))?
+        }
+        Some(PomodoroCommand::Skip) => {
+            task::block_on(run_client_command(PomodoroSocketCommand::Skip))?
+        }
+        Some(PomodoroCommand::Reset) => {
+            task::block_on(run_client_command(PomodoroSocketCommand::Reset))?
+        }
+        Some(PomodoroCommand::Quit) => {
+            task::block_on(run_client_command(PomodoroSocketCommand::Quit))?
+        }
+    }
     Ok(())
 }
 
+fn socket_path() -> anyhow::Result<PathBuf> {
+    let dirs = directories::ProjectDirs::from("", "", "porors")
+        .ok_or_else(|| anyhow::anyhow!("could not determine a runtime directory for porors"))?;
+    let runtime_dir = dirs.runtime_dir().unwrap_or_else(|| dirs.cache_dir());
+    Ok(runtime_dir.join("porors.sock"))
+}
+
+async fn run_client_command(command: PomodoroSocketCommand) -> anyhow::Result<()> {
+    let status = send_socket_command(command).await?;
+    println!(
+        "{} (session {}{})\nRemaining: {}",
+        status.session_kind,
+        status.session_index,
+        if status.paused { ", paused" } else { "" },
+        humantime::format_duration(status.remaining_time),
+    );
+    Ok(())
+}
+
+async fn send_socket_command(command: PomodoroSocketCommand) -> anyhow::Result<PomodoroStatus> {
+    let path = socket_path()?;
+    let mut stream = UnixStream::connect(&path)
+        .await
+        .map_err(|err| anyhow::anyhow!("failed to connect to porors daemon at {path:?}: {err}"))?;
+    stream.write_all(&serde_json::to_vec(&command)?).await?;
+    stream.shutdown(Shutdown::Write)?;
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response).await?;
+    Ok(serde_json::from_slice(&response)?)
+}
+
 struct PomodoroApplication {
     config: PomodoroConfig,
     paused: bool,
     event_stream: PomodoroEventStream,
     current_session: PomodoroSession,
+    completed_work_sessions: usize,
+    awaiting_confirmation: bool,
 }
 
 impl PomodoroApplication {
-    fn new(config: PomodoroConfig) -> anyhow::Result<Self> {
+    fn new(config: PomodoroConfig, socket_path: Option<PathBuf>) -> anyhow::Result<Self> {
         let initial_session = PomodoroSession::for_index(1, &config);
         let tick_interval = config.tick_interval;
         Ok(Self {
             config,
             paused: false,
             current_session: initial_session,
-            event_stream: PomodoroEventStream::new(tick_interval)?,
+            completed_work_sessions: 0,
+            awaiting_confirmation: false,
+            event_stream: PomodoroEventStream::new(tick_interval, socket_path)?,
         })
     }
 
     async fn run(mut self) -> anyhow::Result<()> {
         self.init()?;
         while let Some(event) = self.event_stream.next().await {
-            match event {
+            let should_quit = match event {
                 PomodoroEvent::Error(err) => return Err(err),
-                PomodoroEvent::Quit => break,
-                PomodoroEvent::TogglePause => self.toggle_pause(),
+                PomodoroEvent::Quit => true,
+                PomodoroEvent::TogglePause => {
+                    self.toggle_pause();
+                    false
+                }
                 PomodoroEvent::Tick => self.tick()?,
+                PomodoroEvent::Command(command, stream) => {
+                    self.handle_socket_command(command, stream).await?
+                }
+                PomodoroEvent::Confirm(proceed) => self.confirm(proceed)?,
+            };
+            if should_quit {
+                break;
             }
             self.update_display()?;
         }
@@ -72,10 +146,17 @@ impl PomodoroApplication {
     }
 
     fn update_display(&self) -> anyhow::Result<()> {
-        let display_text = self
-            .config
-            .formatter
-            .format_session(&self.current_session, self.paused);
+        let display_text = if self.awaiting_confirmation {
+            let next_session_kind = SessionKind::for_index(
+                self.current_session.index + 1,
+                self.config.work_sessions_before_long_break,
+            );
+            self.config.formatter.format_prompt(next_session_kind)
+        } else {
+            self.config
+                .formatter
+                .format_session(&self.current_session, self.paused)
+        };
         execute!(
             stdout(),
             RestorePosition,
@@ -89,15 +170,21 @@ impl PomodoroApplication {
         self.paused = !self.paused;
     }
 
-    fn tick(&mut self) -> anyhow::Result<()> {
-        if self.paused {
-            return Ok(());
+    fn set_awaiting_confirmation(&mut self, awaiting_confirmation: bool) {
+        self.awaiting_confirmation = awaiting_confirmation;
+        self.event_stream
+            .set_awaiting_confirmation(awaiting_confirmation);
+    }
+
+    fn tick(&mut self) -> anyhow::Result<bool> {
+        if self.paused || self.awaiting_confirmation {
+            return Ok(false);
         }
         self.current_session.tick(self.config.tick_interval);
         if self.current_session.is_finished() {
-            self.go_to_next_session()?;
+            return self.go_to_next_session();
         }
-        Ok(())
+        Ok(false)
     }
 
     fn show_session_start_notification(&self) -> anyhow::Result<()> {
@@ -107,7 +194,32 @@ impl PomodoroApplication {
         Ok(())
     }
 
-    fn go_to_next_session(&mut self) -> anyhow::Result<()> {
+    fn go_to_next_session(&mut self) -> anyhow::Result<bool> {
+        if matches!(self.current_session.kind, SessionKind::Work) {
+            self.completed_work_sessions += 1;
+        }
+        if self.config.total_work_sessions == Some(self.completed_work_sessions) {
+            self.config.notifier.notify_completion()?;
+            return Ok(true);
+        }
+        if self.config.confirm_continue {
+            self.set_awaiting_confirmation(true);
+            return Ok(false);
+        }
+        self.advance_to_next_session()?;
+        Ok(false)
+    }
+
+    fn confirm(&mut self, proceed: bool) -> anyhow::Result<bool> {
+        if !proceed {
+            return Ok(true);
+        }
+        self.set_awaiting_confirmation(false);
+        self.advance_to_next_session()?;
+        Ok(false)
+    }
+
+    fn advance_to_next_session(&mut self) -> anyhow::Result<()> {
         self.current_session =
             PomodoroSession::for_index(self.current_session.index + 1, &self.config);
         self.show_session_start_notification()?;
@@ -119,71 +231,270 @@ impl PomodoroApplication {
         terminal::disable_raw_mode()?;
         Ok(())
     }
+
+    async fn handle_socket_command(
+        &mut self,
+        command: PomodoroSocketCommand,
+        mut stream: UnixStream,
+    ) -> anyhow::Result<bool> {
+        let should_quit = match command {
+            PomodoroSocketCommand::Status => false,
+            PomodoroSocketCommand::TogglePause => {
+                self.toggle_pause();
+                false
+            }
+            PomodoroSocketCommand::Skip => {
+                if self.awaiting_confirmation {
+                    self.confirm(true)?
+                } else {
+                    self.go_to_next_session()?
+                }
+            }
+            PomodoroSocketCommand::Reset => {
+                self.current_session =
+                    PomodoroSession::for_index(self.current_session.index, &self.config);
+                self.set_awaiting_confirmation(false);
+                false
+            }
+            PomodoroSocketCommand::Quit => true,
+        };
+        let status = self.status();
+        stream.write_all(&serde_json::to_vec(&status)?).await?;
+        Ok(should_quit)
+    }
+
+    fn status(&self) -> PomodoroStatus {
+        PomodoroStatus {
+            session_kind: self.current_session.kind,
+            session_index: self.current_session.index,
+            paused: self.paused,
+            remaining_time: self.current_session.remaining_time(),
+        }
+    }
 }
 
 struct PomodoroConfig {
     tick_interval: Duration,
+    work_sessions_before_long_break: usize,
+    total_work_sessions: Option<usize>,
+    confirm_continue: bool,
     durations: PomodoroDurations,
     formatter: PomodoroDisplayFormatter,
     notifier: PomodoroNotifier,
 }
 
 impl PomodoroConfig {
-    async fn load() -> anyhow::Result<Self> {
-        let args = PomodoroArgs::parse();
+    async fn load(args: &PomodoroArgs) -> anyhow::Result<Self> {
+        let file = PomodoroFileConfig::load(args.config.as_deref())?;
+
+        let work_sessions_before_long_break = args
+            .work_sessions_before_long_break
+            .or(file.work_sessions_before_long_break)
+            .unwrap_or(4);
+        anyhow::ensure!(
+            work_sessions_before_long_break >= 1,
+            "work_sessions_before_long_break must be at least 1, got {work_sessions_before_long_break}"
+        );
+
+        let total_work_sessions = args.cycles.or(file.cycles);
+        anyhow::ensure!(
+            total_work_sessions != Some(0),
+            "cycles must be at least 1, got 0"
+        );
 
         Ok(Self {
-            tick_interval: args.tick_interval.unwrap_or(Duration::from_secs(1)),
+            tick_interval: args
+                .tick_interval
+                .or(file.tick_interval)
+                .unwrap_or(Duration::from_secs(1)),
+            work_sessions_before_long_break,
+            total_work_sessions,
+            confirm_continue: args
+                .confirm_continue
+                .or(file.confirm_continue)
+                .unwrap_or(false),
             durations: PomodoroDurations {
-                work_session: args.work_duration.unwrap_or(Duration::from_secs(25 * 60)),
-                break_session: args.break_duration.unwrap_or(Duration::from_secs(5 * 60)),
+                work_session: args
+                    .work_duration
+                    .or(file.work_duration)
+                    .unwrap_or(Duration::from_secs(25 * 60)),
+                break_session: args
+                    .break_duration
+                    .or(file.break_duration)
+                    .unwrap_or(Duration::from_secs(5 * 60)),
                 long_break_session: args
                     .long_break_duration
+                    .or(file.long_break_duration)
                     .unwrap_or(Duration::from_secs(10 * 60)),
             },
             notifier: PomodoroNotifier {
                 work_session_notification: (
-                    args.work_notification_icon.unwrap_or("clock".into()),
+                    args.work_notification_icon
+                        .clone()
+                        .or(file.work_notification_icon)
+                        .unwrap_or("clock".into()),
                     args.work_notification_title
+                        .clone()
+                        .or(file.work_notification_title)
                         .unwrap_or("Working time".into()),
                     args.work_notification_body
+                        .clone()
+                        .or(file.work_notification_body)
                         .unwrap_or("Well, the moment has passed, back to work!".into()),
                 )
                     .into(),
                 break_session_notification: (
-                    args.break_notification_icon.unwrap_or("clock".into()),
-                    args.break_notification_title.unwrap_or("Break time".into()),
+                    args.break_notification_icon
+                        .clone()
+                        .or(file.break_notification_icon)
+                        .unwrap_or("clock".into()),
+                    args.break_notification_title
+                        .clone()
+                        .or(file.break_notification_title)
+                        .unwrap_or("Break time".into()),
                     args.break_notification_body
+                        .clone()
+                        .or(file.break_notification_body)
                         .unwrap_or("Drink some water!".into()),
                 )
                     .into(),
                 long_break_session_notification: (
-                    args.long_break_notification_icon.unwrap_or("clock".into()),
+                    args.long_break_notification_icon
+                        .clone()
+                        .or(file.long_break_notification_icon)
+                        .unwrap_or("clock".into()),
                     args.long_break_notification_title
+                        .clone()
+                        .or(file.long_break_notification_title)
                         .unwrap_or("Long break time".into()),
                     args.long_break_notification_body
+                        .clone()
+                        .or(file.long_break_notification_body)
                         .unwrap_or("Go for a walk or eat a snack!".into()),
                 )
                     .into(),
+                completion_notification: (
+                    args.completion_notification_icon
+                        .clone()
+                        .or(file.completion_notification_icon)
+                        .unwrap_or("clock".into()),
+                    args.completion_notification_title
+                        .clone()
+                        .or(file.completion_notification_title)
+                        .unwrap_or("Pomodoro cycle complete".into()),
+                    args.completion_notification_body
+                        .clone()
+                        .or(file.completion_notification_body)
+                        .unwrap_or("Great work! That's every session done.".into()),
+                )
+                    .into(),
             },
             formatter: PomodoroDisplayFormatter {
                 active_display: args
                     .active_display
+                    .clone()
+                    .or(file.active_display)
                     .unwrap_or("{session_kind}\nSession {session_number}\n{timer}\n".into()),
                 paused_display: args
                     .paused_display
+                    .clone()
+                    .or(file.paused_display)
                     .unwrap_or(
                         "{session_kind}\nSession {session_number}\n{timer}\n(Paused)\n".into(),
                     )
                     .into(),
-                work_session_label: args.work_label.unwrap_or("Work".into()),
-                break_session_label: args.break_label.unwrap_or("Break".into()),
-                long_break_session_label: args.long_break_label.unwrap_or("Long break".into()),
+                prompt_display: args
+                    .prompt_display
+                    .clone()
+                    .or(file.prompt_display)
+                    .unwrap_or("Next up: {next_session_kind}. Continue? (y/n)\n".into()),
+                work_session_label: args
+                    .work_label
+                    .clone()
+                    .or(file.work_label)
+                    .unwrap_or("Work".into()),
+                break_session_label: args
+                    .break_label
+                    .clone()
+                    .or(file.break_label)
+                    .unwrap_or("Break".into()),
+                long_break_session_label: args
+                    .long_break_label
+                    .clone()
+                    .or(file.long_break_label)
+                    .unwrap_or("Long break".into()),
             },
         })
     }
 }
 
+#[derive(Default, serde::Deserialize)]
+#[serde(default)]
+struct PomodoroFileConfig {
+    #[serde(with = "humantime_serde::option")]
+    tick_interval: Option<Duration>,
+    #[serde(with = "humantime_serde::option")]
+    work_duration: Option<Duration>,
+    #[serde(with = "humantime_serde::option")]
+    break_duration: Option<Duration>,
+    #[serde(with = "humantime_serde::option")]
+    long_break_duration: Option<Duration>,
+    work_sessions_before_long_break: Option<usize>,
+    cycles: Option<usize>,
+    confirm_continue: Option<bool>,
+
+    work_notification_icon: Option<String>,
+    work_notification_title: Option<String>,
+    work_notification_body: Option<String>,
+
+    break_notification_icon: Option<String>,
+    break_notification_title: Option<String>,
+    break_notification_body: Option<String>,
+
+    long_break_notification_icon: Option<String>,
+    long_break_notification_title: Option<String>,
+    long_break_notification_body: Option<String>,
+
+    completion_notification_icon: Option<String>,
+    completion_notification_title: Option<String>,
+    completion_notification_body: Option<String>,
+
+    active_display: Option<String>,
+    paused_display: Option<String>,
+    prompt_display: Option<String>,
+    work_label: Option<String>,
+    break_label: Option<String>,
+    long_break_label: Option<String>,
+}
+
+impl PomodoroFileConfig {
+    fn load(explicit_path: Option<&std::path::Path>) -> anyhow::Result<Self> {
+        let path = match explicit_path {
+            Some(path) => {
+                anyhow::ensure!(
+                    path.exists(),
+                    "config file {} does not exist",
+                    path.display()
+                );
+                path.to_path_buf()
+            }
+            None => {
+                let Some(dirs) = directories::ProjectDirs::from("", "", "porors") else {
+                    return Ok(Self::default());
+                };
+                let path = dirs.config_dir().join("config.toml");
+                if !path.exists() {
+                    return Ok(Self::default());
+                }
+                path
+            }
+        };
+
+        let contents = std::fs::read_to_string(&path)?;
+        Ok(toml::from_str(&contents)?)
+    }
+}
+
 struct PomodoroDurations {
     work_session: Duration,
     break_session: Duration,
@@ -203,6 +514,7 @@ impl PomodoroDurations {
 struct PomodoroDisplayFormatter {
     active_display: String,
     paused_display: String,
+    prompt_display: String,
     work_session_label: String,
     break_session_label: String,
     long_break_session_label: String,
@@ -232,6 +544,16 @@ impl PomodoroDisplayFormatter {
         .replace('\n', "\n\r")
     }
 
+    fn format_prompt(&self, next_session_kind: SessionKind) -> String {
+        let next_session_kind = FormatItem::Str(self.session_label_for(next_session_kind));
+        let args = HashMap::from([("next_session_kind", next_session_kind)]);
+        dynfmt::SimpleCurlyFormat
+            .format(&self.prompt_display, args)
+            .unwrap()
+            .into_owned()
+            .replace('\n', "\n\r")
+    }
+
     fn session_label_for(&self, session_kind: SessionKind) -> &str {
         match session_kind {
             SessionKind::Work => &self.work_session_label,
@@ -253,6 +575,7 @@ struct PomodoroNotifier {
     work_session_notification: PomodoroNotificationTemplate,
     break_session_notification: PomodoroNotificationTemplate,
     long_break_session_notification: PomodoroNotificationTemplate,
+    completion_notification: PomodoroNotificationTemplate,
 }
 
 impl PomodoroNotifier {
@@ -266,6 +589,11 @@ impl PomodoroNotifier {
         notification.show()?;
         Ok(())
     }
+
+    fn notify_completion(&self) -> anyhow::Result<()> {
+        self.completion_notification.build().show()?;
+        Ok(())
+    }
 }
 
 struct PomodoroNotificationTemplate {
@@ -318,6 +646,12 @@ impl<'a> Serialize for FormatItem<'a> {
 
 #[derive(Parser)]
 struct PomodoroArgs {
+    #[command(subcommand)]
+    command: Option<PomodoroCommand>,
+
+    #[arg(long, value_name = "PATH")]
+    config: Option<std::path::PathBuf>,
+
     #[arg(short = 't', long, value_parser = humantime::parse_duration, value_name = "DURATION")]
     tick_interval: Option<Duration>,
 
@@ -330,6 +664,21 @@ struct PomodoroArgs {
     #[arg(short = 'l', long, value_parser = humantime::parse_duration, value_name = "DURATION")]
     long_break_duration: Option<Duration>,
 
+    #[arg(long, value_name = "COUNT")]
+    work_sessions_before_long_break: Option<usize>,
+
+    #[arg(long, value_name = "COUNT")]
+    cycles: Option<usize>,
+
+    #[arg(
+        long,
+        action = ArgAction::Set,
+        num_args = 0..=1,
+        default_missing_value = "true",
+        value_name = "BOOL"
+    )]
+    confirm_continue: Option<bool>,
+
     #[arg(long, value_name = "ICON")]
     work_notification_icon: Option<String>,
 
@@ -357,12 +706,24 @@ struct PomodoroArgs {
     #[arg(long, value_name = "TEXT")]
     long_break_notification_body: Option<String>,
 
+    #[arg(long, value_name = "ICON")]
+    completion_notification_icon: Option<String>,
+
+    #[arg(long, value_name = "TEXT")]
+    completion_notification_title: Option<String>,
+
+    #[arg(long, value_name = "TEXT")]
+    completion_notification_body: Option<String>,
+
     #[arg(long, value_name = "TEXT")]
     active_display: Option<String>,
 
     #[arg(long, value_name = "TEXT")]
     paused_display: Option<String>,
 
+    #[arg(long, value_name = "TEXT")]
+    prompt_display: Option<String>,
+
     #[arg(long, value_name = "TEXT")]
     work_label: Option<String>,
 
@@ -373,19 +734,50 @@ struct PomodoroArgs {
     long_break_label: Option<String>,
 }
 
+#[derive(Subcommand)]
+enum PomodoroCommand {
+    Daemon,
+    Toggle,
+    Status,
+    Skip,
+    Reset,
+    Quit,
+}
+
+#[derive(Clone, Copy, serde::Serialize, serde::Deserialize)]
+enum PomodoroSocketCommand {
+    TogglePause,
+    Status,
+    Skip,
+    Reset,
+    Quit,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct PomodoroStatus {
+    session_kind: SessionKind,
+    session_index: usize,
+    paused: bool,
+    #[serde(with = "humantime_serde")]
+    remaining_time: Duration,
+}
+
 enum PomodoroEvent {
     Error(anyhow::Error),
     Tick,
     TogglePause,
     Quit,
+    Command(PomodoroSocketCommand, UnixStream),
+    Confirm(bool),
 }
 
 struct PomodoroEventStream {
     underlying_stream: Pin<Box<dyn Stream<Item = PomodoroEvent>>>,
+    awaiting_confirmation: std::sync::Arc<std::sync::atomic::AtomicBool>,
 }
 
 impl PomodoroEventStream {
-    fn new(tick_interval: Duration) -> anyhow::Result<Self> {
+    fn new(tick_interval: Duration, socket_path: Option<PathBuf>) -> anyhow::Result<Self> {
         let signal_stream = Signals::new(vec![
             libc::SIGINT,
             libc::SIGQUIT,
@@ -399,24 +791,98 @@ impl PomodoroEventStream {
         });
         let interval_stream =
             async_std::stream::interval(tick_interval).map(|_| PomodoroEvent::Tick);
-        let terminal_event = EventStream::new().filter_map(|event| match event {
-            Ok(event) if event == Event::Key(KeyCode::Char('p').into()) => {
-                Some(PomodoroEvent::TogglePause)
-            }
-            Ok(event)
-                if event
-                    == Event::Key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL))
-                    || event == Event::Key(KeyCode::Char('q').into()) =>
-            {
-                Some(PomodoroEvent::Quit)
+        let awaiting_confirmation = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let terminal_awaiting_confirmation = std::sync::Arc::clone(&awaiting_confirmation);
+        let terminal_event = EventStream::new().filter_map(move |event| {
+            // `y`/`n` only apply while awaiting a confirmation; everything else,
+            // including the Ctrl-C/`q` quit keys, falls through to the normal
+            // match below so the one hard stop this TUI always honored stays
+            // live for the duration of the prompt too.
+            let awaiting_confirmation =
+                terminal_awaiting_confirmation.load(std::sync::atomic::Ordering::SeqCst);
+            match event {
+                Ok(event) if awaiting_confirmation && event == Event::Key(KeyCode::Char('y').into()) => {
+                    Some(PomodoroEvent::Confirm(true))
+                }
+                Ok(event) if awaiting_confirmation && event == Event::Key(KeyCode::Char('n').into()) => {
+                    Some(PomodoroEvent::Confirm(false))
+                }
+                Ok(event) if event == Event::Key(KeyCode::Char('p').into()) => {
+                    Some(PomodoroEvent::TogglePause)
+                }
+                Ok(event)
+                    if event
+                        == Event::Key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL))
+                        || event == Event::Key(KeyCode::Char('q').into()) =>
+                {
+                    Some(PomodoroEvent::Quit)
+                }
+                Ok(_) => None,
+                Err(err) => Some(PomodoroEvent::Error(err.into())),
             }
-            Ok(_) => None,
-            Err(err) => Some(PomodoroEvent::Error(err.into())),
         });
+        let underlying_stream: Pin<Box<dyn Stream<Item = PomodoroEvent>>> =
+            Box::pin(interval_stream.merge(terminal_event).merge(signal_stream));
+        let underlying_stream = match socket_path {
+            Some(socket_path) => {
+                Box::pin(underlying_stream.merge(Self::command_stream(socket_path)?))
+            }
+            None => underlying_stream,
+        };
         Ok(Self {
-            underlying_stream: Box::pin(interval_stream.merge(terminal_event).merge(signal_stream)),
+            underlying_stream,
+            awaiting_confirmation,
         })
     }
+
+    fn set_awaiting_confirmation(&self, awaiting_confirmation: bool) {
+        self.awaiting_confirmation
+            .store(awaiting_confirmation, std::sync::atomic::Ordering::SeqCst);
+    }
+
+    fn command_stream(socket_path: PathBuf) -> anyhow::Result<impl Stream<Item = PomodoroEvent>> {
+        if socket_path.exists() {
+            std::fs::remove_file(&socket_path)?;
+        }
+        if let Some(parent) = socket_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let listener = task::block_on(UnixListener::bind(&socket_path))?;
+        let (sender, receiver) = async_std::channel::unbounded();
+        task::spawn(async move {
+            let mut incoming = listener.incoming();
+            while let Some(connection) = incoming.next().await {
+                // A bad connection (empty, garbage, or a failed accept) only
+                // drops that connection: it must never reach the daemon as a
+                // fatal PomodoroEvent::Error, or one malformed client would
+                // take down the whole running timer.
+                let mut stream = match connection {
+                    Ok(stream) => stream,
+                    Err(err) => {
+                        eprintln!("porors: failed to accept a control connection: {err}");
+                        continue;
+                    }
+                };
+                let command = match Self::read_command(&mut stream).await {
+                    Ok(command) => command,
+                    Err(err) => {
+                        eprintln!("porors: dropping a malformed control connection: {err}");
+                        continue;
+                    }
+                };
+                if sender.send(PomodoroEvent::Command(command, stream)).await.is_err() {
+                    break;
+                }
+            }
+        });
+        Ok(receiver)
+    }
+
+    async fn read_command(stream: &mut UnixStream) -> anyhow::Result<PomodoroSocketCommand> {
+        let mut payload = Vec::new();
+        stream.read_to_end(&mut payload).await?;
+        Ok(serde_json::from_slice(&payload)?)
+    }
 }
 
 impl Stream for PomodoroEventStream {
@@ -430,21 +896,34 @@ impl Stream for PomodoroEventStream {
     }
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, serde::Serialize, serde::Deserialize)]
 enum SessionKind {
     Work,
     Break,
     LongBreak,
 }
 
+impl std::fmt::Display for SessionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            SessionKind::Work => "Work",
+            SessionKind::Break => "Break",
+            SessionKind::LongBreak => "Long break",
+        })
+    }
+}
+
 impl SessionKind {
-    fn for_index(index: usize) -> Self {
-        if index % 8 == 0 {
-            Self::LongBreak
-        } else if index % 2 == 0 {
-            Self::Break
-        } else {
+    fn for_index(index: usize, work_sessions_before_long_break: usize) -> Self {
+        if index % 2 != 0 {
             Self::Work
+        } else {
+            let completed_work_sessions = index / 2;
+            if completed_work_sessions % work_sessions_before_long_break == 0 {
+                Self::LongBreak
+            } else {
+                Self::Break
+            }
         }
     }
 }
@@ -458,7 +937,7 @@ struct PomodoroSession {
 
 impl PomodoroSession {
     fn for_index(index: usize, config: &PomodoroConfig) -> Self {
-        let kind = SessionKind::for_index(index);
+        let kind = SessionKind::for_index(index, config.work_sessions_before_long_break);
         Self {
             index,
             kind,
@@ -468,7 +947,7 @@ impl PomodoroSession {
     }
 
     fn remaining_time(&self) -> Duration {
-        self.duration - self.elapsed_time
+        self.duration.saturating_sub(self.elapsed_time)
     }
 
     fn is_finished(&self) -> bool {